@@ -17,7 +17,7 @@ fn main() {
         print!(
             "{}{}",
             termion::cursor::Left(bar.get_last_width() as u16),
-            bar.to_string()
+            bar
         );
         sleep(Duration::from_millis(10));
     }
@@ -24,7 +24,7 @@
 //!         print!(
 //!             "{}{}",
 //!             termion::cursor::Left(bar.get_last_width() as u16),
-//!             bar.to_string()
+//!             bar
 //!         );
 //!         sleep(Duration::from_millis(10));
 //!     }
@@ -34,6 +34,9 @@
 //! # #[cfg(not(unix))]
 //! # fn main() {}
 //!```
+#![allow(clippy::needless_doctest_main)]
+
+use std::collections::VecDeque;
 
 /// Represents a progress bar which can be used to get your progress string.
 pub struct Bar {
@@ -45,6 +48,11 @@ pub struct Bar {
     leading_char: char,
     include_percent: bool,
     include_numbers: bool,
+    ascii_only: bool,
+    dots: Option<usize>,
+    dots_label: String,
+    window: Option<usize>,
+    history: VecDeque<usize>,
     previous_text_width: usize,
 }
 
@@ -160,6 +168,24 @@ impl BarBuilder {
         }
         self
     }
+    /// Use only ASCII characters for the bar: `#` for full, `-` for empty and `>` for leading.
+    ///
+    /// Only non-ASCII characters are replaced when rendering, so any ASCII characters set
+    /// before or after this call are kept. This is useful for pipes or terminals that can't
+    /// display UTF-8.
+    ///
+    /// #### Examples
+    /// ```
+    /// use progress_string::BarBuilder;
+    ///
+    /// let mut bar = BarBuilder::new().width(10).ascii_only().build();
+    /// bar.update(50);
+    /// assert_eq!(bar.to_string(), "[####>     ]");
+    /// ```
+    pub fn ascii_only(mut self) -> BarBuilder {
+        self.bar.ascii_only = true;
+        self
+    }
 
     /// Update the bar to include the percent after the bar representation (default `false`).
     ///
@@ -193,6 +219,56 @@ impl BarBuilder {
         self.bar.include_numbers = true;
         self
     }
+    /// Render the progress as a label followed by dots instead of a bar (default `None`).
+    ///
+    /// The number of dots is `max` scaled by the current percent, no brackets are drawn.
+    ///
+    /// #### Examples
+    /// ```
+    /// use progress_string::BarBuilder;
+    ///
+    /// let mut bar = BarBuilder::new().dots(6).build();
+    /// bar.replace(50);
+    /// assert_eq!(bar.to_string(), "Loading...");
+    /// ```
+    pub fn dots(mut self, max: usize) -> BarBuilder {
+        self.bar.dots = Some(max);
+        self
+    }
+    /// Update the label printed before the dots when using `dots` (default "Loading").
+    ///
+    /// #### Examples
+    /// ```
+    /// use progress_string::BarBuilder;
+    ///
+    /// let mut bar = BarBuilder::new().dots(4).dots_label("Working").build();
+    /// bar.replace(100);
+    /// assert_eq!(bar.to_string(), "Working....");
+    /// ```
+    pub fn dots_label(mut self, label: impl Into<String>) -> BarBuilder {
+        self.bar.dots_label = label.into();
+        self
+    }
+    /// Calculate the percent over the last `n` updates instead of against the total
+    /// (default `None`).
+    ///
+    /// The window is `n` units wide and the percent becomes the progress made across the last
+    /// `n` calls to `update` or `replace` divided by `n`, capped at 100%. This is useful when
+    /// there is no fixed total. A window of 0 is treated as 1.
+    ///
+    /// #### Examples
+    /// ```
+    /// use progress_string::BarBuilder;
+    ///
+    /// let mut bar = BarBuilder::new().window_percent(4).include_percent().build();
+    /// bar.update(1);
+    /// bar.update(1);
+    /// // yields [█████████████████████████                         ] 50.00%
+    /// ```
+    pub fn window_percent(mut self, n: usize) -> BarBuilder {
+        self.bar.window = Some(n.max(1));
+        self
+    }
     /// deprecated please use `build`
     #[deprecated]
     pub fn get_bar(self) -> Bar {
@@ -225,6 +301,11 @@ impl Default for Bar {
     ///     leading_char: '█',
     ///     include_percent: false,
     ///     include_numbers: false,
+    ///     ascii_only: false,
+    ///     dots: None,
+    ///     dots_label: "Loading",
+    ///     window: None,
+    ///     history: [],
     ///     previous_text_width: 0
     /// }
     /// ```
@@ -238,6 +319,11 @@ impl Default for Bar {
             leading_char: '█',
             include_percent: false,
             include_numbers: false,
+            ascii_only: false,
+            dots: None,
+            dots_label: String::from("Loading"),
+            window: None,
+            history: VecDeque::new(),
             previous_text_width: 0,
         }
     }
@@ -256,6 +342,7 @@ impl Bar {
     /// ```
     pub fn update(&mut self, to_add: usize) {
         self.previous_text_width = self.get_width();
+        self.record_history();
         self.current_partial += to_add;
     }
     /// Update the current partial by replacing the current value.
@@ -270,8 +357,43 @@ impl Bar {
     /// ```
     pub fn replace(&mut self, new_progress: usize) {
         self.previous_text_width = self.get_width();
+        self.record_history();
         self.current_partial = new_progress;
     }
+    /// Move the `current_partial` to `target` over `steps` frames, returning the rendered
+    /// bar for each frame.
+    ///
+    /// The final frame is always the bar at `target`, a `steps` of 0 is treated as 1. Only
+    /// the move to `target` counts as an update, the frames in between are just rendered.
+    ///
+    /// #### Examples
+    /// ```
+    /// use progress_string::Bar;
+    ///
+    /// let mut bar = Bar::default();
+    /// bar.replace(20);
+    /// for frame in bar.interpolate_to(40, 4) {
+    ///     print!("\r{}", frame);
+    /// }
+    /// assert_eq!(bar.current_partial, 40);
+    /// ```
+    pub fn interpolate_to(&mut self, target: usize, steps: usize) -> Vec<String> {
+        let steps = steps.max(1);
+        let original = self.current_partial;
+        let start = original as f64;
+        let distance = target as f64 - start;
+        let mut frames: Vec<String> = (1..steps)
+            .map(|step| {
+                let value = start + distance * step as f64 / steps as f64;
+                self.current_partial = value.round() as usize;
+                self.to_string()
+            })
+            .collect();
+        self.current_partial = original;
+        self.replace(target);
+        frames.push(self.to_string());
+        frames
+    }
     /// Get the current width of characters in the bar.
     ///
     /// This includes the brackets, spaces and percent if set.
@@ -293,7 +415,92 @@ impl Bar {
     /// assert_eq!(with_percent.get_width(), 60);
     /// ```
     pub fn get_width(&self) -> usize {
-        let mut width: usize = 52;
+        if self.dots.is_some() {
+            return self.dots_label.chars().count() + self.dot_count() + self.suffix_width();
+        }
+        self.width + 2 + self.suffix_width()
+    }
+    /// Get the largest bar `width` that would fit in a line of `line_cols` characters.
+    ///
+    /// This subtracts the brackets and the widest the configured suffixes (percent and/or
+    /// numbers) can get, at 100%, from `line_cols`, returning 0 if they don't fit.
+    ///
+    /// When using `dots` there are no brackets, so the label is subtracted instead and the
+    /// result is the largest `max` that would fit.
+    ///
+    /// #### Examples
+    /// ```
+    /// use progress_string::BarBuilder;
+    ///
+    /// let bar = BarBuilder::new().include_percent().build();
+    /// // 2 brackets + " 100.00%"
+    /// assert_eq!(bar.max_bar_width_for(80), 70);
+    /// assert_eq!(bar.max_bar_width_for(5), 0);
+    /// ```
+    pub fn max_bar_width_for(&self, line_cols: usize) -> usize {
+        let decoration = if self.dots.is_some() {
+            self.dots_label.chars().count()
+        } else {
+            2
+        };
+        line_cols.saturating_sub(decoration + self.max_suffix_width())
+    }
+    /// Similar to `get_width` but gets the value before the last `update` or `replace` call.
+    ///
+    /// This is useful for when you are trying to clear the terminal.
+    pub fn get_last_width(&self) -> usize {
+        self.previous_text_width
+    }
+    /// Get a string that will wipe the previously rendered bar from the current line.
+    ///
+    /// This is a carriage return, followed by `get_last_width` spaces and another carriage return.
+    ///
+    /// #### Examples
+    /// ```
+    /// use progress_string::Bar;
+    ///
+    /// let mut bar = Bar::default();
+    /// bar.update(10);
+    /// print!("{}", bar.clear_string());
+    /// println!("a log line");
+    /// ```
+    pub fn clear_string(&self) -> String {
+        format!("\r{}\r", " ".repeat(self.previous_text_width))
+    }
+    /// Get a compact representation of the progress for use as a window title.
+    ///
+    /// The percent is rounded down so the title never reads 100% before the work is done.
+    ///
+    /// #### Examples
+    /// ```
+    /// use progress_string::Bar;
+    ///
+    /// let mut bar = Bar::default();
+    /// bar.update(42);
+    /// assert_eq!(bar.title_string("myapp"), "42% - myapp");
+    /// ```
+    pub fn title_string(&self, app: &str) -> String {
+        let (partial, whole) = self.percent_parts();
+        let percent = partial.saturating_mul(100).checked_div(whole).unwrap_or(0);
+        format!("{}% - {}", percent, app)
+    }
+    /// Similar to `title_string` but wrapped in the escape sequence that sets the terminal's
+    /// window title.
+    ///
+    /// #### Examples
+    /// ```
+    /// use progress_string::Bar;
+    ///
+    /// let mut bar = Bar::default();
+    /// bar.update(42);
+    /// print!("{}", bar.osc_title_string("myapp"));
+    /// ```
+    pub fn osc_title_string(&self, app: &str) -> String {
+        format!("\x1b]0;{}\x07", self.title_string(app))
+    }
+
+    fn suffix_width(&self) -> usize {
+        let mut width: usize = 0;
         if self.include_numbers {
             let total_string = format!("{}", self.total);
             let partial_string = format!("{}", self.current_partial);
@@ -311,15 +518,61 @@ impl Bar {
         }
         width
     }
-    /// Similar to `get_width` but gets the value before the last `update` or `replace` call.
-    ///
-    /// This is useful for when you are trying to clear the terminal.
-    pub fn get_last_width(&self) -> usize {
-        self.previous_text_width
+
+    fn max_suffix_width(&self) -> usize {
+        let mut width: usize = 0;
+        if self.include_numbers {
+            width += format!("{}", self.total).len() * 2 + 2;
+        }
+        if self.include_percent {
+            width += 8;
+        }
+        width
     }
 
     fn calculate_percent(&self) -> f32 {
-        self.current_partial as f32 / self.total as f32
+        let (partial, whole) = self.percent_parts();
+        partial as f32 / whole as f32
+    }
+
+    fn percent_parts(&self) -> (usize, usize) {
+        if let Some(window) = self.window {
+            let oldest = self
+                .history
+                .front()
+                .copied()
+                .unwrap_or(self.current_partial);
+            let progress = self.current_partial.saturating_sub(oldest).min(window);
+            return (progress, window);
+        }
+        (self.current_partial, self.total)
+    }
+
+    fn record_history(&mut self) {
+        if let Some(window) = self.window {
+            self.history.push_back(self.current_partial);
+            while self.history.len() > window {
+                self.history.pop_front();
+            }
+        }
+    }
+
+    fn bar_char(&self, character: char, ascii: char) -> char {
+        if self.ascii_only && !character.is_ascii() {
+            ascii
+        } else {
+            character
+        }
+    }
+
+    fn dot_count(&self) -> usize {
+        let max = self.dots.unwrap_or(0);
+        let (partial, whole) = self.percent_parts();
+        partial
+            .saturating_mul(max)
+            .checked_div(whole)
+            .unwrap_or(0)
+            .min(max)
     }
 }
 
@@ -344,17 +597,27 @@ impl std::fmt::Display for Bar {
     /// ```
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let percent = self.calculate_percent();
-        f.write_str("[")?;
-        for i in 0..self.width {
-            if (i as f32) < ((self.width as f32 * percent) - 1.0) {
-                f.write_fmt(format_args!("{}", self.full_char))?;
-            } else if (i as f32) < (self.width as f32 * percent) {
-                f.write_fmt(format_args!("{}", self.leading_char))?;
-            } else {
-                f.write_fmt(format_args!("{}", self.empty_char))?;
+        if self.dots.is_some() {
+            f.write_str(&self.dots_label)?;
+            for _ in 0..self.dot_count() {
+                f.write_str(".")?;
+            }
+        } else {
+            let full_char = self.bar_char(self.full_char, '#');
+            let leading_char = self.bar_char(self.leading_char, '>');
+            let empty_char = self.bar_char(self.empty_char, '-');
+            f.write_str("[")?;
+            for i in 0..self.width {
+                if (i as f32) < ((self.width as f32 * percent) - 1.0) {
+                    f.write_fmt(format_args!("{}", full_char))?;
+                } else if (i as f32) < (self.width as f32 * percent) {
+                    f.write_fmt(format_args!("{}", leading_char))?;
+                } else {
+                    f.write_fmt(format_args!("{}", empty_char))?;
+                }
             }
+            f.write_str("]")?;
         }
-        f.write_str("]")?;
         if self.include_percent {
             f.write_fmt(format_args!(" {:.2}%", percent * 100.0))?;
         }
@@ -461,6 +724,29 @@ mod tests {
         )
     }
     #[test]
+    fn max_bar_width_for_test() {
+        let bar = BarBuilder::new()
+            .include_percent()
+            .include_numbers()
+            .build();
+        // [] + " 100.00%" + " 100/100"
+        assert_eq!(bar.max_bar_width_for(80), 80 - 2 - 8 - 8);
+        assert_eq!(bar.max_bar_width_for(10), 0);
+        let mut sized = BarBuilder::new()
+            .width(bar.max_bar_width_for(80))
+            .include_percent()
+            .include_numbers()
+            .build();
+        sized.replace(100);
+        assert_eq!(sized.to_string().chars().count(), 80);
+        let plain = Bar::default();
+        assert_eq!(plain.max_bar_width_for(80), 78);
+        // "Loading" + " 100.00%"
+        let dots = BarBuilder::new().dots(6).include_percent().build();
+        assert_eq!(dots.max_bar_width_for(80), 80 - 7 - 8);
+        assert_eq!(dots.max_bar_width_for(10), 0);
+    }
+    #[test]
     fn leading_char() {
         let mut bar = BarBuilder::new().leading_char('>').build();
         assert_eq!(
@@ -486,4 +772,175 @@ mod tests {
             "[█████████████████████████                         ]"
         )
     }
+    #[test]
+    fn dots() {
+        let mut bar = BarBuilder::new().dots(6).build();
+        assert_eq!(bar.to_string(), "Loading");
+        bar.update(50);
+        assert_eq!(bar.to_string(), "Loading...");
+        assert_eq!(bar.get_width(), 10);
+        bar.update(50);
+        assert_eq!(bar.to_string(), "Loading......");
+    }
+    #[test]
+    fn dots_exact_count() {
+        let mut bar = BarBuilder::new().total(22).dots(22).build();
+        bar.replace(13);
+        assert_eq!(bar.to_string(), format!("Loading{}", ".".repeat(13)));
+        let mut bar = BarBuilder::new().total(23).dots(23).build();
+        bar.replace(7);
+        assert_eq!(bar.to_string(), format!("Loading{}", ".".repeat(7)));
+    }
+    #[test]
+    fn clear_string() {
+        let mut bar = BarBuilder::new().include_percent().build();
+        bar.update(50);
+        let clear = bar.clear_string();
+        assert_eq!(clear.len(), bar.get_last_width() + 2);
+        assert!(clear.starts_with('\r'));
+        assert!(clear.ends_with('\r'));
+        assert!(clear.trim_matches('\r').chars().all(|c| c == ' '));
+        let mut wide = BarBuilder::new().width(100).build();
+        wide.update(50);
+        assert_eq!(wide.clear_string(), format!("\r{}\r", " ".repeat(102)));
+    }
+    #[test]
+    fn window_percent() {
+        let mut bar = BarBuilder::new().window_percent(10).build();
+        assert_eq!(bar.calculate_percent(), 0.0);
+        let mut double = BarBuilder::new().window_percent(10).build();
+        bar.update(1);
+        double.update(2);
+        assert_eq!(bar.calculate_percent(), 0.1);
+        assert_eq!(double.calculate_percent(), 0.2);
+        double.update(1_000_000);
+        assert_eq!(double.calculate_percent(), 1.0);
+        for _ in 0..4 {
+            bar.update(1);
+        }
+        assert_eq!(bar.calculate_percent(), 0.5);
+        for _ in 0..10 {
+            bar.update(1);
+        }
+        assert_eq!(bar.calculate_percent(), 1.0);
+        for _ in 0..5 {
+            bar.update(0);
+        }
+        assert_eq!(bar.calculate_percent(), 0.5);
+        for _ in 0..3 {
+            bar.update(2);
+        }
+        // window holds 13, 14, 15, 15, 15, 15, 15, 15, 17, 19 with 21 current
+        assert_eq!(bar.calculate_percent(), 0.8);
+        assert_eq!(
+            bar.to_string(),
+            "[████████████████████████████████████████          ]"
+        );
+        bar.replace(0);
+        assert_eq!(bar.calculate_percent(), 0.0);
+    }
+    #[test]
+    fn window_percent_zero() {
+        let mut bar = BarBuilder::new()
+            .window_percent(0)
+            .include_percent()
+            .build();
+        assert_eq!(bar.to_string(), format!("[{}] 0.00%", " ".repeat(50)));
+        bar.update(1);
+        assert_eq!(bar.calculate_percent(), 1.0);
+        assert_eq!(bar.get_width(), 60);
+        bar.update(0);
+        assert_eq!(bar.calculate_percent(), 0.0);
+    }
+    #[test]
+    fn ascii_only() {
+        let mut bar = BarBuilder::new()
+            .ascii_only()
+            .include_percent()
+            .include_numbers()
+            .build();
+        for _ in 0..4 {
+            assert!(bar.to_string().is_ascii());
+            bar.update(25);
+        }
+        assert_eq!(
+            bar.to_string(),
+            "[#################################################>] 100.00% 100/100"
+        );
+    }
+    #[test]
+    fn ascii_only_keeps_ascii_chars() {
+        let mut bar = BarBuilder::new()
+            .width(10)
+            .full_char('=')
+            .ascii_only()
+            .build();
+        bar.update(50);
+        assert_eq!(bar.to_string(), "[====>     ]");
+        let mut bar = BarBuilder::new()
+            .width(10)
+            .ascii_only()
+            .empty_char('.')
+            .build();
+        bar.update(50);
+        assert_eq!(bar.to_string(), "[####>.....]");
+    }
+    #[test]
+    fn ascii_only_before_char_setters() {
+        let mut bar = BarBuilder::new()
+            .width(10)
+            .ascii_only()
+            .full_char('█')
+            .leading_char('▶')
+            .empty_char('░')
+            .build();
+        for _ in 0..4 {
+            assert!(bar.to_string().is_ascii());
+            bar.update(25);
+        }
+        assert_eq!(bar.to_string(), "[#########>]");
+    }
+    #[test]
+    fn title_string() {
+        let mut bar = BarBuilder::new().total(1000).build();
+        bar.update(290);
+        let title = bar.title_string("myapp");
+        assert!(title.contains("29%"));
+        assert!(title.contains("myapp"));
+        assert_eq!(title, "29% - myapp");
+        assert_eq!(bar.osc_title_string("myapp"), "\x1b]0;29% - myapp\x07");
+        bar.replace(999);
+        assert_eq!(bar.title_string("myapp"), "99% - myapp");
+        bar.replace(1000);
+        assert_eq!(bar.title_string("myapp"), "100% - myapp");
+    }
+    #[test]
+    fn interpolate_to() {
+        let mut bar = Bar::default();
+        bar.replace(20);
+        let frames = bar.interpolate_to(40, 4);
+        assert_eq!(frames.len(), 4);
+        let fills: Vec<usize> = frames
+            .iter()
+            .map(|frame| frame.chars().filter(|c| *c == '█').count())
+            .collect();
+        assert!(fills.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(bar.current_partial, 40);
+        assert_eq!(frames.last().unwrap(), &bar.to_string());
+        assert_eq!(fills[3], 20);
+    }
+    #[test]
+    fn interpolate_to_window_percent() {
+        let mut bar = BarBuilder::new().window_percent(10).build();
+        for _ in 0..10 {
+            bar.update(1);
+        }
+        assert_eq!(bar.calculate_percent(), 1.0);
+        let width = bar.get_width();
+        let frames = bar.interpolate_to(11, 10);
+        assert_eq!(frames.len(), 10);
+        assert_eq!(bar.current_partial, 11);
+        assert_eq!(bar.calculate_percent(), 1.0);
+        assert_eq!(bar.get_last_width(), width);
+    }
 }